@@ -0,0 +1,121 @@
+//! Layered config file (`~/.config/zkli/config.toml`) with named server aliases, so users don't
+//! have to paste long multi-host connection strings on every invocation.
+//!
+//! ```toml
+//! timeout = 5
+//! prod = "zk1:2181,zk2:2181/app"
+//! %include "base.toml"
+//! ```
+//!
+//! `%include path` pulls in another config file (relative paths resolve against the including
+//! file), merging its aliases before this file's own entries are layered on top as overrides.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use miette::{miette, IntoDiagnostic, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+struct RawConfig {
+    #[serde(flatten)]
+    aliases: HashMap<String, String>,
+    timeout: Option<u64>,
+}
+
+impl RawConfig {
+    fn merge(&mut self, other: RawConfig) {
+        self.aliases.extend(other.aliases);
+        if other.timeout.is_some() {
+            self.timeout = other.timeout;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Config {
+    aliases: HashMap<String, String>,
+    pub timeout: Option<u64>,
+}
+
+impl Config {
+    /// Resolve the connection string to use, per: explicit `--addr` literal > matching alias
+    /// name > default `localhost:2181/`.
+    pub fn resolve_addr(&self, explicit: Option<&str>) -> String {
+        match explicit {
+            Some(addr) => self
+                .aliases
+                .get(addr)
+                .cloned()
+                .unwrap_or_else(|| addr.to_string()),
+            None => String::from("localhost:2181/"),
+        }
+    }
+}
+
+/// Load `~/.config/zkli/config.toml`, following `%include` directives. Returns the default
+/// (empty) config when no file exists.
+pub fn load() -> Result<Config> {
+    let Some(path) = default_path() else {
+        return Ok(Config::default());
+    };
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let mut visited = HashSet::new();
+    let raw = read_file(&path, &mut visited)?;
+    Ok(Config {
+        aliases: raw.aliases,
+        timeout: raw.timeout,
+    })
+}
+
+fn default_path() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("zkli").join("config.toml"))
+}
+
+/// `visited` holds the canonicalized path of every file already entered along the current
+/// `%include` chain, so a config that includes itself (directly or transitively) errors out
+/// instead of recursing until the stack overflows.
+fn read_file(path: &Path, visited: &mut HashSet<PathBuf>) -> Result<RawConfig> {
+    let canonical = path.canonicalize().into_diagnostic()?;
+    if !visited.insert(canonical.clone()) {
+        return Err(miette!(
+            "`%include` cycle detected: `{}` includes itself, directly or transitively.",
+            path.display()
+        ));
+    }
+
+    let result = read_file_uncycled(path, visited);
+    // Only the current include chain must stay cycle-free, not the whole tree: a shared base
+    // file included from several per-user configs (a diamond, not a cycle) must stay legal.
+    visited.remove(&canonical);
+    result
+}
+
+fn read_file_uncycled(path: &Path, visited: &mut HashSet<PathBuf>) -> Result<RawConfig> {
+    let content = std::fs::read_to_string(path).into_diagnostic()?;
+
+    let mut merged = RawConfig::default();
+    let mut toml_lines = Vec::new();
+    for line in content.lines() {
+        match line.trim().strip_prefix("%include") {
+            Some(include_path) => {
+                let include_path = include_path.trim().trim_matches('"');
+                let resolved = path
+                    .parent()
+                    .unwrap_or_else(|| Path::new("."))
+                    .join(include_path);
+                merged.merge(read_file(&resolved, visited)?);
+            }
+            None => toml_lines.push(line),
+        }
+    }
+
+    let parsed: RawConfig = toml::from_str(&toml_lines.join("\n")).into_diagnostic()?;
+    merged.merge(parsed);
+    Ok(merged)
+}
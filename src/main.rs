@@ -1,13 +1,20 @@
 use std::{
     fmt::Display,
     io::{stdin, stdout, Read, Write},
+    sync::mpsc,
     time::Duration,
 };
 
+use chrono::Local;
 use clap::{Parser, ValueEnum};
 use colored::{ColoredString, Colorize};
 use miette::{miette, Context, IntoDiagnostic, Result};
-use zookeeper::{Acl, ZooKeeper};
+use serde::{Deserialize, Serialize};
+use xz2::{read::XzDecoder, write::XzEncoder};
+use zookeeper::{Acl, WatchedEvent, WatchedEventType, ZooKeeper};
+
+mod config;
+mod mount;
 
 pub fn get_styles() -> clap::builder::Styles {
     clap::builder::Styles::styled()
@@ -31,8 +38,15 @@ pub fn get_styles() -> clap::builder::Styles {
 #[clap(about = "Cli around zookeeper")]
 #[command(styles = get_styles())]
 struct Options {
-    #[clap(long, short, default_value_t = String::from("localhost:2181/"))]
-    pub addr: String,
+    /// Server to connect to, either a full connection string or an alias name defined in
+    /// `~/.config/zkli/config.toml`. Defaults to `localhost:2181/`.
+    #[clap(long, short)]
+    pub addr: Option<String>,
+
+    /// Add an authentication scheme to the session, as `scheme:credential`
+    /// (e.g. `digest:user:pass`). Required to access nodes on a secured ensemble.
+    #[clap(long)]
+    pub auth: Option<String>,
 
     #[clap(subcommand)]
     pub command: Command,
@@ -83,6 +97,10 @@ enum Command {
         /// - If you don't send any content, erase the content of the file for nothing.
         #[clap(long, short, default_value_t = false)]
         force: bool,
+        /// ACL entries (`scheme:id:permissions`, perms any of `crdwa`) to apply when `--force`
+        /// creates the node. Defaults to `world:anyone:crdwa` (anyone can do anything).
+        #[clap(long)]
+        acl: Vec<String>,
     },
     /// Create a new file.
     /// Write the content of stdin or argv to the specified path.
@@ -96,6 +114,78 @@ enum Command {
         /// Mode to use when creating the file.
         #[clap(long, default_values_t = vec![CreateMode::Persistent])]
         mode: Vec<CreateMode>,
+        /// ACL entries (`scheme:id:permissions`, perms any of `crdwa`). Defaults to
+        /// `world:anyone:crdwa` (anyone can do anything).
+        #[clap(long)]
+        acl: Vec<String>,
+    },
+    /// Rename/move entries. ZooKeeper has no native rename so this copies the data, ACL and
+    /// mode to the destination and only deletes the source once the copy succeeded.
+    #[clap(aliases = &["rename", "move"])]
+    Mv {
+        /// Path(s) of the entries to move. A single `*` wildcard can be used to batch-move
+        /// matches, with `#1` in `dest` substituted with the matched segment.
+        sources: Vec<String>,
+        /// Destination path, or pattern containing `#1` when `sources` uses a wildcard.
+        dest: String,
+        /// Move the whole subtree recursively.
+        #[clap(long, short, default_value_t = false)]
+        recursive: bool,
+        /// Overwrite the destination's data if it already exists (its ACL and mode are kept).
+        #[clap(long, short, default_value_t = false)]
+        force: bool,
+    },
+    /// Duplicate a node, or with `--recursive` a whole subtree, leaving the source untouched.
+    #[clap(aliases = &["copy"])]
+    Cp {
+        /// Path of the entry to copy.
+        src: String,
+        /// Destination path. Missing ancestors are created as empty persistent nodes.
+        dest: String,
+        /// Copy the whole subtree recursively.
+        #[clap(long, short, default_value_t = false)]
+        recursive: bool,
+    },
+    /// Serialize a subtree to a compressed archive, for backup or promotion to another cluster.
+    Export {
+        /// Path of the subtree to export.
+        path: String,
+        /// File to write the archive to. Defaults to stdout.
+        output: Option<String>,
+    },
+    /// Restore a subtree from an archive produced by `export`.
+    Import {
+        /// File to read the archive from. Defaults to stdin.
+        input: Option<String>,
+        /// Path to restore the subtree under.
+        dest: String,
+        /// Overwrite nodes that already exist instead of skipping them.
+        #[clap(long, short, default_value_t = false)]
+        force: bool,
+    },
+    /// Stream node and (optionally) child-change events for a path, like `tail -f` for a znode.
+    Watch {
+        /// Path to watch.
+        path: String,
+        /// Also watch for children being added or removed.
+        #[clap(long, short, default_value_t = false)]
+        children: bool,
+    },
+    /// Mount a subtree as a read-only FUSE filesystem, so it can be browsed with `ls`/`cat`.
+    Mount {
+        /// Path of the subtree to expose.
+        path: String,
+        /// Directory to mount the filesystem at.
+        mountpoint: String,
+    },
+    /// Print the ACL of a node, or replace it when new entries are given.
+    #[clap(aliases = &["chmod", "getacl"])]
+    Acl {
+        /// Path of the node.
+        path: String,
+        /// New ACL entries (`scheme:id:permissions`, perms any of `crdwa`) to apply. Prints the
+        /// current ACL when empty.
+        set: Vec<String>,
     },
 }
 
@@ -106,6 +196,15 @@ pub enum CreateMode {
     Sequential,
 }
 
+/// One node of an exported subtree, flattened for archival.
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportEntry {
+    /// Path of the node relative to the exported root, e.g. `/` or `/child`.
+    relative_path: String,
+    data: Vec<u8>,
+    ephemeral: bool,
+}
+
 impl Display for CreateMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -122,9 +221,14 @@ fn main() -> Result<()> {
     log_builder.parse_filters("warn");
     log_builder.init();
 
-    log::info!("Connecting to {}", opt.addr);
-    let zk = ZooKeeper::connect(&opt.addr, Duration::from_secs(1), |_| ()).into_diagnostic()?;
+    let config = config::load()?;
+    let addr = config.resolve_addr(opt.addr.as_deref());
+    let timeout = Duration::from_secs(config.timeout.unwrap_or(1));
+
+    log::info!("Connecting to {addr}");
+    let zk = ZooKeeper::connect(&addr, timeout, |_| ()).into_diagnostic()?;
     log::info!("Connected");
+    apply_auth(&zk, opt.auth.as_deref())?;
 
     match opt.command {
         Command::Ls { path } => {
@@ -187,6 +291,7 @@ fn main() -> Result<()> {
             mut path,
             content,
             force,
+            acl,
         } => {
             sanitize_path(&mut path);
             let mut buffer = content
@@ -200,13 +305,9 @@ fn main() -> Result<()> {
             match zk.set_data(&path, buffer.clone(), None) {
                 Ok(_) => (),
                 Err(zookeeper::ZkError::NoNode) if force => {
-                    zk.create(
-                        &path,
-                        buffer,
-                        Acl::open_unsafe().clone(),
-                        zookeeper::CreateMode::Persistent,
-                    )
-                    .into_diagnostic()?;
+                    let acl = parse_acls_or_open_unsafe(&acl)?;
+                    zk.create(&path, buffer, acl, zookeeper::CreateMode::Persistent)
+                        .into_diagnostic()?;
                 }
                 err => {
                     err.into_diagnostic()?;
@@ -217,6 +318,7 @@ fn main() -> Result<()> {
             mut path,
             content,
             mode,
+            acl,
         } => {
             sanitize_path(&mut path);
             let mut buffer = content
@@ -240,12 +342,152 @@ fn main() -> Result<()> {
                 (false, true, true) => zookeeper::CreateMode::EphemeralSequential,
                 (false, true, false) => zookeeper::CreateMode::Ephemeral,
             };
-            let ret = zk
-                .create(&path, buffer, Acl::open_unsafe().clone(), mode)
-                .into_diagnostic()?;
+            let acl = parse_acls_or_open_unsafe(&acl)?;
+            let ret = zk.create(&path, buffer, acl, mode).into_diagnostic()?;
 
             println!("{ret}");
         }
+        Command::Mv {
+            sources,
+            dest,
+            recursive,
+            force,
+        } => {
+            for source in sources {
+                let ret = || -> Result<()> {
+                    if source.contains('*') {
+                        for (matched_source, captured) in expand_wildcard(&zk, &source)? {
+                            let mut matched_dest = dest.replace("#1", &captured);
+                            sanitize_path(&mut matched_dest);
+                            mv(&zk, &matched_source, &matched_dest, recursive, force)?;
+                        }
+                    } else {
+                        let mut source = source.clone();
+                        let mut dest = dest.clone();
+                        sanitize_path(&mut source);
+                        sanitize_path(&mut dest);
+                        mv(&zk, &source, &dest, recursive, force)?;
+                    }
+                    Ok(())
+                }();
+                if let Err(e) = ret {
+                    log::error!("`{}`: {}", source, e);
+                }
+            }
+        }
+        Command::Cp {
+            mut src,
+            mut dest,
+            recursive,
+        } => {
+            sanitize_path(&mut src);
+            sanitize_path(&mut dest);
+            if recursive {
+                if is_same_or_descendant(&dest, &src) {
+                    return Err(miette!(
+                        "Cannot copy `{src}` recursively into its own subtree `{dest}`."
+                    ));
+                }
+                cp_subtree(&zk, &src, &dest)?;
+            } else {
+                cp_node(&zk, &src, &dest)?;
+            }
+        }
+        Command::Export { mut path, output } => {
+            sanitize_path(&mut path);
+            let entries = collect_entries(&zk, &path, &path)?;
+            let bytes = bincode::serialize(&entries).into_diagnostic()?;
+
+            let writer: Box<dyn Write> = match &output {
+                Some(output) => Box::new(std::fs::File::create(output).into_diagnostic()?),
+                None => Box::new(stdout()),
+            };
+            let mut encoder = XzEncoder::new(writer, 9);
+            encoder.write_all(&bytes).into_diagnostic()?;
+            encoder.finish().into_diagnostic()?;
+        }
+        Command::Import {
+            input,
+            mut dest,
+            force,
+        } => {
+            sanitize_path(&mut dest);
+            let reader: Box<dyn Read> = match &input {
+                Some(input) => Box::new(std::fs::File::open(input).into_diagnostic()?),
+                None => Box::new(stdin()),
+            };
+            let mut bytes = Vec::new();
+            XzDecoder::new(reader)
+                .read_to_end(&mut bytes)
+                .into_diagnostic()?;
+            let mut entries: Vec<ExportEntry> = bincode::deserialize(&bytes).into_diagnostic()?;
+            entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+            for entry in entries {
+                if entry.ephemeral {
+                    continue;
+                }
+                let path = if entry.relative_path == "/" {
+                    dest.clone()
+                } else if dest == "/" {
+                    // `relative_path` already carries its own leading `/` (see `collect_entries`),
+                    // so restoring under root must not concatenate a second one.
+                    entry.relative_path.clone()
+                } else {
+                    format!("{dest}{}", entry.relative_path)
+                };
+                let ret = || -> Result<()> {
+                    create_parents(&zk, &path)?;
+                    match zk.create(
+                        &path,
+                        entry.data.clone(),
+                        Acl::open_unsafe().clone(),
+                        zookeeper::CreateMode::Persistent,
+                    ) {
+                        Ok(_) => Ok(()),
+                        Err(zookeeper::ZkError::NodeExists) if force => {
+                            zk.set_data(&path, entry.data, None).into_diagnostic()
+                        }
+                        err => err.into_diagnostic().map(|_| ()),
+                    }
+                }();
+                if let Err(e) = ret {
+                    log::error!("`{}`: {}", path, e);
+                }
+            }
+        }
+        Command::Watch { mut path, children } => {
+            sanitize_path(&mut path);
+
+            let (tx, rx) = mpsc::channel();
+            let watch_zk = ZooKeeper::connect(&addr, timeout, move |event| {
+                let _ = tx.send(event);
+            })
+            .into_diagnostic()?;
+            apply_auth(&watch_zk, opt.auth.as_deref())?;
+
+            arm_watch(&watch_zk, &path, children)?;
+            for event in rx {
+                print_watched_event(&watch_zk, &event);
+                arm_watch(&watch_zk, &path, children)?;
+            }
+        }
+        Command::Mount { mut path, mountpoint } => {
+            sanitize_path(&mut path);
+            mount::mount(zk, path, &mountpoint)?;
+        }
+        Command::Acl { mut path, set } => {
+            sanitize_path(&mut path);
+            if set.is_empty() {
+                let (acl, _) = zk.get_acl(&path).into_diagnostic()?;
+                for entry in acl {
+                    println!("{}:{}:{}", entry.scheme, entry.id, format_perms(entry.perms));
+                }
+            } else {
+                let acl = parse_acls(&set)?;
+                zk.set_acl(&path, acl, None).into_diagnostic()?;
+            }
+        }
     }
     Ok(())
 }
@@ -292,6 +534,303 @@ fn recursive_delete(zk: &ZooKeeper, path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Move `source` to `dest`, recreating it (and, with `recursive`, its whole subtree) at the
+/// destination before deleting the source, so a failure midway never loses data.
+fn mv(zk: &ZooKeeper, source: &str, dest: &str, recursive: bool, force: bool) -> Result<()> {
+    if source == dest {
+        // Moving a node onto itself (e.g. a wildcard batch whose `#1` substitution reconstructs
+        // the source path) is a no-op: copying then deleting would destroy the node instead.
+        return Ok(());
+    }
+    if recursive {
+        if is_same_or_descendant(dest, source) {
+            return Err(miette!(
+                "Cannot move `{source}` recursively into its own subtree `{dest}`."
+            ));
+        }
+        mv_copy_subtree(zk, source, dest, force)?;
+        recursive_delete(zk, source)?;
+    } else {
+        mv_copy_node(zk, source, dest, force)?;
+        zk.delete(source, None).into_diagnostic()?;
+    }
+    Ok(())
+}
+
+/// Recreate a single node at `dest` with the same data, ACL and mode (persistent/ephemeral) as
+/// `source`, without touching the source. When `dest` already exists and `force` is set, only
+/// its data is overwritten: its existing ACL and mode are kept.
+fn mv_copy_node(zk: &ZooKeeper, source: &str, dest: &str, force: bool) -> Result<()> {
+    let exists = zk.exists(dest, false).into_diagnostic()?.is_some();
+    if exists && !force {
+        return Err(miette!(
+            "`{dest}` already exists, use `--force` to overwrite it."
+        ));
+    }
+    let (data, stat) = zk.get_data(source, false).into_diagnostic()?;
+    if exists {
+        zk.set_data(dest, data, None).into_diagnostic()?;
+    } else {
+        let acl = zk.get_acl(source).into_diagnostic()?.0;
+        let mode = if stat.is_ephemeral() {
+            zookeeper::CreateMode::Ephemeral
+        } else {
+            zookeeper::CreateMode::Persistent
+        };
+        zk.create(dest, data, acl, mode).into_diagnostic()?;
+    }
+    Ok(())
+}
+
+fn mv_copy_subtree(zk: &ZooKeeper, source: &str, dest: &str, force: bool) -> Result<()> {
+    mv_copy_node(zk, source, dest, force)?;
+
+    let source = if source == "/" { "" } else { source };
+    let dest = if dest == "/" { "" } else { dest };
+    let children = zk.get_children(source, false).into_diagnostic()?;
+    for child in children {
+        mv_copy_subtree(
+            zk,
+            &format!("{source}/{child}"),
+            &format!("{dest}/{child}"),
+            force,
+        )?;
+    }
+    Ok(())
+}
+
+/// Copy a single node's data to `dest`, creating any missing ancestor of `dest` as an empty
+/// persistent node first, since ZooKeeper `create` fails if the parent doesn't exist yet.
+fn cp_node(zk: &ZooKeeper, src: &str, dest: &str) -> Result<()> {
+    let (data, _) = zk.get_data(src, false).into_diagnostic()?;
+    create_parents(zk, dest)?;
+    zk.create(
+        dest,
+        data,
+        Acl::open_unsafe().clone(),
+        zookeeper::CreateMode::Persistent,
+    )
+    .into_diagnostic()?;
+    Ok(())
+}
+
+fn cp_subtree(zk: &ZooKeeper, src: &str, dest: &str) -> Result<()> {
+    cp_node(zk, src, dest)?;
+
+    let src = if src == "/" { "" } else { src };
+    let dest = if dest == "/" { "" } else { dest };
+    let children = zk.get_children(src, false).into_diagnostic()?;
+    for child in children {
+        cp_subtree(zk, &format!("{src}/{child}"), &format!("{dest}/{child}"))?;
+    }
+    Ok(())
+}
+
+/// Create every ancestor of `path` that doesn't exist yet, as empty persistent nodes.
+fn create_parents(zk: &ZooKeeper, path: &str) -> Result<()> {
+    let Some((parent, _)) = path.rsplit_once('/') else {
+        return Ok(());
+    };
+    if parent.is_empty() || parent == "/" {
+        return Ok(());
+    }
+    if zk.exists(parent, false).into_diagnostic()?.is_none() {
+        create_parents(zk, parent)?;
+        zk.create(
+            parent,
+            Vec::new(),
+            Acl::open_unsafe().clone(),
+            zookeeper::CreateMode::Persistent,
+        )
+        .into_diagnostic()?;
+    }
+    Ok(())
+}
+
+/// (Re-)register a watch on `path`, and on its children too when `watch_children` is set, so the
+/// next matching `WatchedEvent` is delivered to the watcher given at connect time.
+fn arm_watch(zk: &ZooKeeper, path: &str, watch_children: bool) -> Result<()> {
+    zk.exists(path, true).into_diagnostic()?;
+    if watch_children {
+        zk.get_children(path, true).into_diagnostic()?;
+    }
+    Ok(())
+}
+
+/// Print one `WatchedEvent` as a timestamped, colored line, along with the node's current
+/// stat/data when it's still around to fetch.
+fn print_watched_event(zk: &ZooKeeper, event: &WatchedEvent) {
+    let timestamp = Local::now().format("%H:%M:%S%.3f");
+    let path = event.path.as_deref().unwrap_or("-");
+    let kind = format!("{:?}", event.event_type);
+    let kind = match event.event_type {
+        WatchedEventType::NodeCreated => kind.green(),
+        WatchedEventType::NodeDeleted => kind.red(),
+        WatchedEventType::NodeDataChanged => kind.yellow(),
+        WatchedEventType::NodeChildrenChanged => kind.cyan(),
+        _ => kind.normal(),
+    };
+    println!("{} {} {}", format!("[{timestamp}]").dimmed(), kind.bold(), path);
+
+    if event.event_type == WatchedEventType::NodeDeleted {
+        return;
+    }
+    if let Ok(Some(stat)) = zk.exists(path, false) {
+        println!("  {}", format_node_from_stat(path, &stat));
+        if stat.data_length > 0 && event.event_type == WatchedEventType::NodeDataChanged {
+            if let Ok((data, _)) = zk.get_data(path, false) {
+                match String::from_utf8(data) {
+                    Ok(s) => println!("  {s}"),
+                    Err(_) => println!("  <binary data>"),
+                }
+            }
+        }
+    }
+}
+
+/// Walk the subtree rooted at `path` (reusing the same depth-first order as `tree`), flattening
+/// it into export entries whose `relative_path` is `path` with the `root` prefix stripped.
+fn collect_entries(zk: &ZooKeeper, root: &str, path: &str) -> Result<Vec<ExportEntry>> {
+    let (data, stat) = zk.get_data(path, false).into_diagnostic()?;
+    // `root == "/"` can't be stripped as a prefix without also eating the leading `/` of every
+    // other entry, so leave `path` (already absolute) as-is in that case.
+    let relative_path = if path == root {
+        String::from("/")
+    } else if root == "/" {
+        path.to_string()
+    } else {
+        path.strip_prefix(root).unwrap_or(path).to_string()
+    };
+    let mut entries = vec![ExportEntry {
+        relative_path,
+        data,
+        ephemeral: stat.is_ephemeral(),
+    }];
+
+    let path_prefix = if path == "/" { "" } else { path };
+    let mut children = zk.get_children(path, false).into_diagnostic()?;
+    children.sort();
+    for child in children {
+        entries.extend(collect_entries(zk, root, &format!("{path_prefix}/{child}"))?);
+    }
+    Ok(entries)
+}
+
+/// Expand a single `*` wildcard in `pattern` against the children of its parent path, returning
+/// each matched absolute path alongside the segment it captured (for substitution into `#1`).
+fn expand_wildcard(zk: &ZooKeeper, pattern: &str) -> Result<Vec<(String, String)>> {
+    let mut pattern = pattern.to_string();
+    sanitize_path(&mut pattern);
+    let (dir, name_pattern) = pattern.rsplit_once('/').unwrap_or(("", pattern.as_str()));
+    let dir = if dir.is_empty() { "/" } else { dir };
+    let (prefix, suffix) = name_pattern
+        .split_once('*')
+        .ok_or_else(|| miette!("`{pattern}` must contain a `*` wildcard."))?;
+
+    let mut children = zk.get_children(dir, false).into_diagnostic()?;
+    children.sort();
+
+    let mut matches = Vec::new();
+    for child in children {
+        if let Some(captured) = child
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+        {
+            let path = if dir == "/" {
+                format!("/{child}")
+            } else {
+                format!("{dir}/{child}")
+            };
+            matches.push((path, captured.to_string()));
+        }
+    }
+    Ok(matches)
+}
+
+/// Parse `--acl` tokens, falling back to `Acl::open_unsafe()` (anyone can do anything) when none
+/// were given, matching this command's previous hardcoded default.
+fn parse_acls_or_open_unsafe(tokens: &[String]) -> Result<Vec<Acl>> {
+    if tokens.is_empty() {
+        Ok(Acl::open_unsafe().clone())
+    } else {
+        parse_acls(tokens)
+    }
+}
+
+fn parse_acls(tokens: &[String]) -> Result<Vec<Acl>> {
+    tokens.iter().map(|token| parse_acl(token)).collect()
+}
+
+/// Parse one `scheme:id:permissions` token (perms any of `crdwa`) into an `Acl`.
+fn parse_acl(token: &str) -> Result<Acl> {
+    // The id itself can contain colons (the canonical `digest` id is `username:base64hash`), so
+    // only the scheme is split from the front; permissions are split off the back instead.
+    let invalid = || miette!("Invalid ACL `{token}`, expected `scheme:id:permissions`.");
+    let (scheme, rest) = token.split_once(':').ok_or_else(invalid)?;
+    let (id, perms) = rest.rsplit_once(':').ok_or_else(invalid)?;
+
+    let mut bits = 0;
+    for c in perms.chars() {
+        bits |= match c {
+            'c' => zookeeper::perms::CREATE,
+            'r' => zookeeper::perms::READ,
+            'w' => zookeeper::perms::WRITE,
+            'd' => zookeeper::perms::DELETE,
+            'a' => zookeeper::perms::ADMIN,
+            _ => {
+                return Err(miette!(
+                    "Invalid ACL permission `{c}` in `{token}`, expected any of `crdwa`."
+                ))
+            }
+        };
+    }
+
+    Ok(Acl {
+        perms: bits,
+        scheme: scheme.to_string(),
+        id: id.to_string(),
+    })
+}
+
+fn format_perms(perms: i32) -> String {
+    let mut s = String::new();
+    if perms & zookeeper::perms::CREATE != 0 {
+        s.push('c');
+    }
+    if perms & zookeeper::perms::READ != 0 {
+        s.push('r');
+    }
+    if perms & zookeeper::perms::DELETE != 0 {
+        s.push('d');
+    }
+    if perms & zookeeper::perms::WRITE != 0 {
+        s.push('w');
+    }
+    if perms & zookeeper::perms::ADMIN != 0 {
+        s.push('a');
+    }
+    s
+}
+
+/// Apply `--auth` (`scheme:credential`) to a connection. `watch` opens its own connection
+/// independent from the primary one, so this is called once per `ZooKeeper` instance.
+fn apply_auth(zk: &ZooKeeper, auth: Option<&str>) -> Result<()> {
+    let Some(auth) = auth else {
+        return Ok(());
+    };
+    let (scheme, credential) = auth
+        .split_once(':')
+        .ok_or_else(|| miette!("Invalid `--auth`, expected `scheme:credential`."))?;
+    zk.add_auth(scheme, credential.as_bytes().to_vec())
+        .into_diagnostic()?;
+    Ok(())
+}
+
+/// Whether `path` is `ancestor` itself or lives anywhere under it.
+fn is_same_or_descendant(path: &str, ancestor: &str) -> bool {
+    path == ancestor || ancestor == "/" || path.starts_with(&format!("{ancestor}/"))
+}
+
 fn sanitize_path(path: &mut String) {
     if !path.starts_with("/") {
         log::warn!(
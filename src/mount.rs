@@ -0,0 +1,287 @@
+//! Read-only FUSE view over a ZooKeeper subtree: `zkli mount /app/config /mnt/zk` lets you
+//! `ls`/`cat` znodes with ordinary tools.
+
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fuser::{
+    FileAttr, FileType, Filesystem, ReplyAttr, ReplyData, ReplyDirectory, ReplyEntry, Request,
+};
+use libc::{EIO, ENOENT, EROFS};
+
+const TTL: Duration = Duration::from_secs(1);
+const ROOT_INODE: u64 = 1;
+/// Synthetic file exposing the payload of a znode that also has children.
+const DATA_FILE_NAME: &str = ".data";
+
+/// A node reachable through the mount: either the znode itself, or its `.data` payload when the
+/// znode has children (ZK nodes can hold data and children at once, unlike regular files).
+#[derive(Debug, Clone)]
+enum Entry {
+    Node(String),
+    Data(String),
+}
+
+pub struct ZkFs {
+    zk: zookeeper::ZooKeeper,
+    root: String,
+    inodes: HashMap<u64, Entry>,
+    paths: HashMap<String, u64>,
+    next_inode: u64,
+}
+
+impl ZkFs {
+    pub fn new(zk: zookeeper::ZooKeeper, root: String) -> Self {
+        let mut inodes = HashMap::new();
+        let mut paths = HashMap::new();
+        inodes.insert(ROOT_INODE, Entry::Node(root.clone()));
+        paths.insert(root.clone(), ROOT_INODE);
+        Self {
+            zk,
+            root,
+            inodes,
+            paths,
+            next_inode: ROOT_INODE + 1,
+        }
+    }
+
+    /// Look up (or lazily allocate) the inode for a znode path.
+    fn inode_for_node(&mut self, path: &str) -> u64 {
+        if let Some(&ino) = self.paths.get(path) {
+            return ino;
+        }
+        let ino = self.next_inode;
+        self.next_inode += 1;
+        self.inodes.insert(ino, Entry::Node(path.to_string()));
+        self.paths.insert(path.to_string(), ino);
+        ino
+    }
+
+    /// Look up (or lazily allocate) the inode for the synthetic `.data` file of a znode.
+    fn inode_for_data(&mut self, path: &str) -> u64 {
+        let key = format!("{path}\0{DATA_FILE_NAME}");
+        if let Some(&ino) = self.paths.get(&key) {
+            return ino;
+        }
+        let ino = self.next_inode;
+        self.next_inode += 1;
+        self.inodes.insert(ino, Entry::Data(path.to_string()));
+        self.paths.insert(key, ino);
+        ino
+    }
+
+    fn child_path(parent: &str, name: &str) -> String {
+        if parent == "/" {
+            format!("/{name}")
+        } else {
+            format!("{parent}/{name}")
+        }
+    }
+
+    fn attr_for_node(&self, ino: u64, path: &str) -> Option<FileAttr> {
+        let stat = self.zk.exists(path, false).ok()??;
+        let kind = if stat.num_children > 0 {
+            FileType::Directory
+        } else {
+            FileType::RegularFile
+        };
+        Some(build_attr(ino, stat.data_length as u64, kind, &stat))
+    }
+
+    fn attr_for_data(&self, ino: u64, path: &str) -> Option<FileAttr> {
+        let stat = self.zk.exists(path, false).ok()??;
+        Some(build_attr(ino, stat.data_length as u64, FileType::RegularFile, &stat))
+    }
+}
+
+impl Filesystem for ZkFs {
+    fn lookup(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEntry) {
+        let Some(name) = name.to_str() else {
+            reply.error(ENOENT);
+            return;
+        };
+        let Some(Entry::Node(parent_path)) = self.inodes.get(&parent).cloned() else {
+            reply.error(ENOENT);
+            return;
+        };
+
+        let child_path = Self::child_path(&parent_path, name);
+        let real_child_exists = self.zk.exists(&child_path, false).ok().flatten().is_some();
+
+        // `.data` is synthetic only as long as no real znode is actually named `.data`; a real
+        // child with that name always takes precedence so it's never permanently shadowed.
+        if name == DATA_FILE_NAME && !real_child_exists {
+            let ino = self.inode_for_data(&parent_path);
+            match self.attr_for_data(ino, &parent_path) {
+                Some(attr) => reply.entry(&TTL, &attr, 0),
+                None => reply.error(ENOENT),
+            }
+            return;
+        }
+
+        if !real_child_exists {
+            reply.error(ENOENT);
+            return;
+        }
+        let ino = self.inode_for_node(&child_path);
+        match self.attr_for_node(ino, &child_path) {
+            Some(attr) => reply.entry(&TTL, &attr, 0),
+            None => reply.error(ENOENT),
+        }
+    }
+
+    fn getattr(&mut self, _req: &Request<'_>, ino: u64, _fh: Option<u64>, reply: ReplyAttr) {
+        let Some(entry) = self.inodes.get(&ino).cloned() else {
+            reply.error(ENOENT);
+            return;
+        };
+        let attr = match &entry {
+            Entry::Node(path) => self.attr_for_node(ino, path),
+            Entry::Data(path) => self.attr_for_data(ino, path),
+        };
+        match attr {
+            Some(attr) => reply.attr(&TTL, &attr),
+            None => reply.error(ENOENT),
+        }
+    }
+
+    fn read(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        size: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: ReplyData,
+    ) {
+        let path = match self.inodes.get(&ino) {
+            Some(Entry::Node(path)) | Some(Entry::Data(path)) => path.clone(),
+            None => {
+                reply.error(ENOENT);
+                return;
+            }
+        };
+        let Ok((data, _)) = self.zk.get_data(&path, false) else {
+            reply.error(EIO);
+            return;
+        };
+        let offset = offset.max(0) as usize;
+        let end = offset.saturating_add(size as usize).min(data.len());
+        let slice = if offset < data.len() {
+            &data[offset..end]
+        } else {
+            &[]
+        };
+        reply.data(slice);
+    }
+
+    fn readdir(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        mut reply: ReplyDirectory,
+    ) {
+        let Some(Entry::Node(path)) = self.inodes.get(&ino).cloned() else {
+            reply.error(ENOENT);
+            return;
+        };
+        let Ok(mut children) = self.zk.get_children(&path, false) else {
+            reply.error(EIO);
+            return;
+        };
+        children.sort();
+
+        let mut entries = vec![
+            (ino, FileType::Directory, ".".to_string()),
+            (ino, FileType::Directory, "..".to_string()),
+        ];
+        // Only synthesize `.data` when no real child already claims that name, so a real znode
+        // literally named `.data` is listed once (as itself) rather than shadowed or duplicated.
+        if !children.iter().any(|child| child == DATA_FILE_NAME) {
+            entries.push((
+                self.inode_for_data(&path),
+                FileType::RegularFile,
+                DATA_FILE_NAME.to_string(),
+            ));
+        }
+        for child in children {
+            let child_path = Self::child_path(&path, &child);
+            let child_ino = self.inode_for_node(&child_path);
+            // Report the same `d_type` `getattr`/`lookup` would: tools like `find -type d` trust
+            // readdir's type without re-stat'ing every entry.
+            let kind = match self.zk.exists(&child_path, false) {
+                Ok(Some(stat)) if stat.num_children > 0 => FileType::Directory,
+                _ => FileType::RegularFile,
+            };
+            entries.push((child_ino, kind, child));
+        }
+
+        for (i, (ino, kind, name)) in entries.into_iter().enumerate().skip(offset as usize) {
+            if reply.add(ino, (i + 1) as i64, kind, name) {
+                break;
+            }
+        }
+        reply.ok();
+    }
+
+    fn write(
+        &mut self,
+        _req: &Request<'_>,
+        _ino: u64,
+        _fh: u64,
+        _offset: i64,
+        _data: &[u8],
+        _write_flags: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: fuser::ReplyWrite,
+    ) {
+        reply.error(EROFS);
+    }
+}
+
+fn build_attr(ino: u64, size: u64, kind: FileType, stat: &zookeeper::Stat) -> FileAttr {
+    let mut mtime = UNIX_EPOCH + Duration::from_millis(stat.mtime.max(0) as u64);
+    if stat.is_ephemeral() {
+        mtime += Duration::from_secs(1);
+    }
+    FileAttr {
+        ino,
+        size,
+        blocks: size.div_ceil(512),
+        atime: mtime,
+        mtime,
+        ctime: mtime,
+        crtime: mtime,
+        kind,
+        perm: if kind == FileType::Directory {
+            0o555
+        } else {
+            0o444
+        },
+        nlink: 1,
+        uid: 0,
+        gid: 0,
+        rdev: 0,
+        blksize: 512,
+        flags: 0,
+    }
+}
+
+/// Mount `root` read-only at `mountpoint` until unmounted or the process exits.
+pub fn mount(zk: zookeeper::ZooKeeper, root: String, mountpoint: &str) -> miette::Result<()> {
+    use miette::IntoDiagnostic;
+
+    let options = vec![
+        fuser::MountOption::RO,
+        fuser::MountOption::FSName("zkli".to_string()),
+    ];
+    fuser::mount2(ZkFs::new(zk, root), mountpoint, &options).into_diagnostic()
+}